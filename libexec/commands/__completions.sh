@@ -0,0 +1,132 @@
+#!/usr/bin/env bash
+#
+# __completions.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+
+define BASH_COMPLETION <<'EOF'
+_realm() {
+  local cur="${COMP_WORDS[COMP_CWORD]}"
+  local prev="${COMP_WORDS[COMP_CWORD-1]}"
+  local cmd=""
+  local sub=""
+  local i
+
+  if [[ "$prev" == "-c" || "$prev" == "--config" ]]; then
+    COMPREPLY=($(compgen -f -- "$cur"))
+    return
+  fi
+
+  for ((i = 1; i < COMP_CWORD; i++)); do
+    case ${COMP_WORDS[i]} in
+      -c|--config)
+        ((i++))
+        ;;
+      -*)
+        ;;
+      *)
+        if [[ -z "$cmd" ]]; then
+          cmd="${COMP_WORDS[i]}"
+        elif [[ -z "$sub" ]]; then
+          sub="${COMP_WORDS[i]}"
+        fi
+        ;;
+    esac
+  done
+
+  case $cmd in
+    "")
+      COMPREPLY=($(compgen -W "setup check init edit config exec completions --config" -- "$cur"))
+      ;;
+    "init")
+      COMPREPLY=($(compgen -W "flutter go node rust --detect --force --name" -- "$cur"))
+      ;;
+    "edit")
+      COMPREPLY=($(compgen -W "alias functions hooks paths" -- "$cur"))
+      ;;
+    "config")
+      case $sub in
+        "")
+          COMPREPLY=($(compgen -W "set get del unset validate print" -- "$cur"))
+          ;;
+        "get")
+          COMPREPLY=($(compgen -W "--json" -- "$cur"))
+          ;;
+        "validate")
+          COMPREPLY=($(compgen -W "--strict" -- "$cur"))
+          ;;
+        "print")
+          COMPREPLY=($(compgen -W "--format --json --show-secrets shell dotenv json yaml" -- "$cur"))
+          ;;
+      esac
+      ;;
+    "exec")
+      COMPREPLY=($(compgen -c -- "$cur"))
+      ;;
+    "completions")
+      COMPREPLY=($(compgen -W "bash zsh fish" -- "$cur"))
+      ;;
+  esac
+}
+
+complete -F _realm realm
+EOF
+
+define FISH_COMPLETION <<'EOF'
+complete -c realm -f
+complete -c realm -s c -l config -r -F -d 'Use an alternate config file'
+
+complete -c realm -n __fish_use_subcommand -a setup -d 'Set up your machine'
+complete -c realm -n __fish_use_subcommand -a check -d 'Check the realm config'
+complete -c realm -n __fish_use_subcommand -a init -d 'Initialize a project'
+complete -c realm -n __fish_use_subcommand -a edit -d 'Edit the shell stack'
+complete -c realm -n __fish_use_subcommand -a config -d 'Manage the realm config'
+complete -c realm -n __fish_use_subcommand -a exec -d 'Run a command with the project env'
+complete -c realm -n __fish_use_subcommand -a completions -d 'Print shell completions'
+
+complete -c realm -n '__fish_seen_subcommand_from init' -a 'flutter go node rust'
+complete -c realm -n '__fish_seen_subcommand_from init' -s d -l detect -d 'Detect the project type'
+complete -c realm -n '__fish_seen_subcommand_from init' -s f -l force -d 'Regenerate realm files'
+complete -c realm -n '__fish_seen_subcommand_from init' -s n -l name -r -d 'Project name'
+
+complete -c realm -n '__fish_seen_subcommand_from edit' -a 'alias functions hooks paths'
+
+complete -c realm -n '__fish_seen_subcommand_from config' -a 'set get del unset validate print'
+complete -c realm -n '__fish_seen_subcommand_from config' -l json -d 'Output JSON'
+complete -c realm -n '__fish_seen_subcommand_from config' -l strict -d 'Treat warnings as errors'
+complete -c realm -n '__fish_seen_subcommand_from config' -l format -x -a 'shell dotenv json yaml'
+complete -c realm -n '__fish_seen_subcommand_from config' -l show-secrets -d 'Do not mask secrets'
+
+complete -c realm -n '__fish_seen_subcommand_from exec' -a '(__fish_complete_command)'
+
+complete -c realm -n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'
+EOF
+
+shell=$1
+shift
+
+case $shell in
+  "bash")
+    echo "$BASH_COMPLETION"
+    ;;
+  "zsh")
+    echo "autoload -U +X bashcompinit && bashcompinit"
+    echo ""
+    echo "$BASH_COMPLETION"
+    ;;
+  "fish")
+    echo "$FISH_COMPLETION"
+    ;;
+  *)
+    echo "Invalid shell, use one of: bash, zsh, fish." | error
+    exit 1
+    ;;
+esac
+
+unset BASH_COMPLETION
+unset FISH_COMPLETION
@@ -15,24 +15,45 @@ if [[ -f "$REALM_CONFIG" ]]; then
   source "$REALM_CONFIG"
 fi
 
-AUTHOR="${REALM_AUTHOR}"
-NAME="${REALM_NAME}"
-EMAIL="${REALM_EMAIL}"
-COMPANY="${REALM_COMPANY}"
-EDITOR="${REALM_EDITOR}"
-
-exports=(
-"export REALM_AUTHOR=\"$AUTHOR\""
-"export REALM_NAME=\"$NAME\""
-"export REALM_EMAIL=\"$EMAIL\""
-"export REALM_COMPANY=\"$COMPANY\""
-"export REALM_EDITOR=\"$EDITOR\""
-)
+CONFIG_KEYS=(REALM_AUTHOR REALM_NAME REALM_EMAIL REALM_COMPANY REALM_EDITOR)
+
+shell_escape() {
+  printf '%s' "$1" | sed 's/[\\"$`]/\\&/g'
+}
+
+exports=()
+
+if [[ -f "$REALM_CONFIG" ]]; then
+  while IFS= read -r line || [[ -n "$line" ]]; do
+    if [[ "$line" == export\ * ]]; then
+      exports+=("$line")
+    fi
+  done < "$REALM_CONFIG"
+
+  unset line
+fi
+
+for key in "${CONFIG_KEYS[@]}"; do
+  found=0
+
+  for e in "${exports[@]}"; do
+    if [[ "$e" == "export ${key}="* ]]; then
+      found=1
+    fi
+  done
+
+  if [[ $found -eq 0 ]]; then
+    exports+=("export ${key}=\"$(shell_escape "${!key}")\"")
+  fi
+done
+
+unset key
+unset found
 
 write_config() {
   echo "Writing config..." | status
 
-  mkdir -p "${REALM_HOME}"
+  mkdir -p "$(dirname "${REALM_CONFIG}")"
   echo "#!/usr/bin/env bash" > "${REALM_CONFIG}"
 
   for e in "${exports[@]}"; do
@@ -41,33 +62,315 @@ write_config() {
   done
 }
 
+check_name() {
+  local var="$1"
+
+  if [[ ! "$var" =~ ^[A-Za-z_][A-Za-z0-9_]*$ ]]; then
+    echo "Invalid config name '${var}', use letters, digits and underscores." | error >&2
+    exit 1
+  fi
+}
+
 set_var() {
   local var="$1"
   local value="$2"
 
-  exports+=("export ${var}=\"${value}\"")
+  check_name "$var"
 
-  source "$REALM_CONFIG"
+  if [[ "$value" == *$'\n'* || "$value" == *$'\r'* ]]; then
+    echo "Config values can't contain newlines." | error >&2
+    exit 1
+  fi
+
+  value="$(shell_escape "$value")"
+
+  del_var "$var"
+  exports+=("export ${var}=\"${value}\"")
 }
 
 del_var() {
   local var="$1"
 
+  check_name "$var"
+
   for i in "${!exports[@]}"; do
-    if [[ "${exports[$i]}" == "export ${var}=\"${!var}\"" ]]; then
-      unset exports[$i]
+    if [[ "${exports[$i]}" == "export ${var}="* ]]; then
+      unset 'exports[$i]'
     fi
   done
 }
 
-dump_config() {
-  CFG=$(cat "${REALM_CONFIG}")
+get_var() {
+  local var="$1"
+  local format="$2"
+  local value
+
+  check_name "$var"
+
+  if ! grep -q "^export ${var}=" "${REALM_CONFIG}"; then
+    echo "${var} is not set in ${REALM_CONFIG}" | error >&2
+    exit 1
+  fi
+
+  value="$(unescaped_value "$var")"
+
+  if [[ "$format" == "--json" ]]; then
+    json_string "$value"
+  else
+    echo "$value"
+  fi
+}
+
+config_value() {
+  local var="$1"
+
+  sed -n "s/^export ${var}=\"\(.*\)\"$/\1/p" "${REALM_CONFIG}" | tail -n 1
+}
+
+unescaped_value() {
+  local var="$1"
+
+  config_value "$var" | sed 's/\\\(.\)/\1/g'
+}
+
+is_secret() {
+  local key
+
+  key="$(echo "$1" | tr '[:lower:]' '[:upper:]')"
+
+  [[ "$key" == *_KEY || "$key" == *_SECRET || "$key" == *_TOKEN || "$key" == *PASSWORD* ]]
+}
+
+masked_value() {
+  local key="$1"
+
+  if [[ $show_secrets -eq 0 ]] && is_secret "$key"; then
+    echo "****"
+  else
+    unescaped_value "$key"
+  fi
+}
 
-  CONFIGS=($CFG)
+json_string() {
+  local value="$1"
+  local out=""
+  local char
+  local code
+  local i
 
-  for e in "${#CONFIGS[@]}"; do
-    echo "$e"
+  for ((i = 0; i < ${#value}; i++)); do
+    char="${value:i:1}"
+
+    case $char in
+      '"')
+        out+='\"'
+        ;;
+      '\')
+        out+='\\'
+        ;;
+      $'\n')
+        out+='\n'
+        ;;
+      $'\r')
+        out+='\r'
+        ;;
+      $'\t')
+        out+='\t'
+        ;;
+      *)
+        printf -v code '%d' "'${char}"
+
+        if [[ $code -ge 0 && $code -lt 32 ]]; then
+          printf -v char '\\u%04x' "$code"
+        fi
+
+        out+="$char"
+        ;;
+    esac
   done
+
+  echo "\"${out}\""
+}
+
+closest_key() {
+  local key="$1"
+
+  printf '%s\n' "${CONFIG_KEYS[@]}" | awk -v key="$key" '
+    function min(a, b) { return a < b ? a : b }
+    function distance(s, t,    i, j, d, cost) {
+      for (i = 0; i <= length(s); i++) d[i, 0] = i
+      for (j = 0; j <= length(t); j++) d[0, j] = j
+      for (i = 1; i <= length(s); i++)
+        for (j = 1; j <= length(t); j++) {
+          cost = substr(s, i, 1) == substr(t, j, 1) ? 0 : 1
+          d[i, j] = min(min(d[i - 1, j] + 1, d[i, j - 1] + 1), d[i - 1, j - 1] + cost)
+        }
+      return d[length(s), length(t)]
+    }
+    {
+      dist = distance(key, $0)
+      if (best == "" || dist < best_dist) { best = $0; best_dist = dist }
+    }
+    END { if (best_dist <= 3) print best }'
+}
+
+validate_config() {
+  local pattern='^export [A-Za-z_][A-Za-z0-9_]*="([^"\\$`]|\\.)*"$'
+  local strict=0
+  local errors=0
+  local line_no=0
+  local line
+  local key
+  local message
+  local suggestion
+  local value
+
+  if [[ "$1" == "--strict" ]]; then
+    strict=1
+  fi
+
+  if [[ ! -f "$REALM_CONFIG" ]]; then
+    echo "${REALM_CONFIG} does not exist, run 'realm check' to create it." | error
+    exit 1
+  fi
+
+  while IFS= read -r line || [[ -n "$line" ]]; do
+    line_no=$((line_no + 1))
+
+    if [[ -z "${line// }" || "$line" == \#* ]]; then
+      continue
+    fi
+
+    if [[ ! "$line" =~ $pattern ]]; then
+      echo "${REALM_CONFIG}:${line_no}: expected export NAME=\"value\"" | error
+      errors=$((errors + 1))
+      continue
+    fi
+
+    key="${line#export }"
+    key="${key%%=*}"
+
+    if [[ "$key" != REALM_* || " ${CONFIG_KEYS[*]} " == *" ${key} "* ]]; then
+      continue
+    fi
+
+    message="${REALM_CONFIG}:${line_no}: unknown key ${key}"
+    suggestion="$(closest_key "$key")"
+
+    if [[ -n "$suggestion" ]]; then
+      message="${message}, did you mean ${suggestion}?"
+    fi
+
+    if [[ $strict -eq 1 ]]; then
+      echo "$message" | error
+      errors=$((errors + 1))
+    else
+      echo "$message" | warning
+    fi
+  done < "$REALM_CONFIG"
+
+  for var in "${CONFIG_KEYS[@]}"; do
+    if [[ -z "$(config_value "$var")" ]]; then
+      echo "${REALM_CONFIG}: ${var} is missing or empty" | error
+      errors=$((errors + 1))
+    fi
+  done
+
+  value="$(config_value REALM_EMAIL)"
+  if [[ -n "$value" && ! "$value" =~ ^[^@]+@[^@]+\.[^@]+$ ]]; then
+    echo "${REALM_CONFIG}: REALM_EMAIL '${value}' does not look like an email" | warning
+  fi
+
+  value="$(config_value REALM_EDITOR)"
+  if [[ -n "$value" ]] && ! command -v "$value" >/dev/null 2>&1; then
+    echo "${REALM_CONFIG}: REALM_EDITOR '${value}' was not found on PATH" | warning
+  fi
+
+  if [[ $errors -gt 0 ]]; then
+    echo "Found ${errors} error(s) in ${REALM_CONFIG}" | error
+    exit 1
+  fi
+
+  echo "${REALM_CONFIG} is valid." | success
+}
+
+print_config() {
+  local format="shell"
+  local keys=()
+  local key
+  local line
+  local value
+  local sep=""
+  local show_secrets=0
+
+  while [[ $# -gt 0 ]]; do
+    case $1 in
+      "--format")
+        format="$2"
+        shift
+        ;;
+      --format=*)
+        format="${1#--format=}"
+        ;;
+      "--json")
+        format="json"
+        ;;
+      "--show-secrets")
+        show_secrets=1
+        ;;
+      *)
+        echo "Invalid print option '${1}'." | error >&2
+        exit 1
+        ;;
+    esac
+    shift
+  done
+
+  while IFS= read -r line || [[ -n "$line" ]]; do
+    if [[ "$line" != export\ * ]]; then
+      continue
+    fi
+
+    key="${line#export }"
+    key="${key%%=*}"
+
+    if [[ " ${keys[*]} " != *" ${key} "* ]]; then
+      keys+=("$key")
+    fi
+  done < "$REALM_CONFIG"
+
+  case $format in
+    "shell")
+      for key in "${keys[@]}"; do
+        echo "export ${key}=\"$(shell_escape "$(masked_value "$key")")\""
+      done
+      ;;
+    "json")
+      echo "{"
+      for key in "${keys[@]}"; do
+        [[ -n "$sep" ]] && echo "$sep"
+        echo -n "  \"${key}\": $(json_string "$(masked_value "$key")")"
+        sep=","
+      done
+      echo ""
+      echo "}"
+      ;;
+    "dotenv")
+      for key in "${keys[@]}"; do
+        value="$(json_string "$(masked_value "$key")")"
+        echo "${key}=${value//\$/\\\$}"
+      done
+      ;;
+    "yaml")
+      for key in "${keys[@]}"; do
+        echo "${key}: $(json_string "$(masked_value "$key")")"
+      done
+      ;;
+    *)
+      echo "Invalid format '${format}', use shell, dotenv, json or yaml." | error
+      exit 1
+      ;;
+  esac
 }
 
 action=$1
@@ -78,11 +381,24 @@ case $action in
     set_var "$1" "$2"
     write_config
     ;;
-  "del")
+  "get")
+    get_var "$1" "$2"
+    ;;
+  "del"|"unset")
     del_var "$1"
     write_config
     ;;
+  "validate")
+    validate_config "$1"
+    ;;
+  "print")
+    print_config "$@"
+    ;;
+  "")
+    print_config
+    ;;
   *)
-    dump_config
+    echo "Invalid config command." | error >&2
+    exit 1
     ;;
 esac
\ No newline at end of file
@@ -0,0 +1,61 @@
+#!/usr/bin/env bash
+#
+# __exec.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+
+if [[ "$1" == "--" ]]; then
+  shift
+fi
+
+if [[ $# -eq 0 ]]; then
+  echo "Missing command to exec." | error
+  exit 1
+fi
+
+envrc_file() {
+  local dir="$(pwd)"
+
+  while true; do
+    if [[ -f "${dir}/.envrc" ]]; then
+      echo "${dir}/.envrc"
+      return
+    fi
+
+    if [[ -z "$dir" || "$dir" == "/" ]]; then
+      return
+    fi
+
+    dir="${dir%/*}"
+  done
+}
+
+# The command gets the caller's environment as it was before realm ran,
+# with the project's .envrc applied on top by direnv. Nothing realm or
+# config.sh exported for itself is passed along.
+(
+  for name in $(compgen -e); do
+    unset "$name" 2>/dev/null
+  done
+
+  eval "$CALLER_ENV" 2>/dev/null
+
+  if command -v direnv >/dev/null 2>&1; then
+    exec direnv exec "$(pwd)" "$@"
+  fi
+
+  ENV_FILE="$(envrc_file)"
+
+  if [[ -n "$ENV_FILE" ]]; then
+    echo "Found ${ENV_FILE} but direnv isn't installed, running without it." | warning >&2
+  fi
+
+  exec "$@"
+)
+
+exit $?
@@ -9,23 +9,148 @@
 # Copywrite (c) 2021 Wess.io
 #
 
+FORCE=0
+DETECT=0
+NAME=""
+ARGS=()
 
+while [[ $# -gt 0 ]]; do
+  case $1 in
+    "-f"|"--force")
+      FORCE=1
+      ;;
+    "-d"|"--detect")
+      DETECT=1
+      ;;
+    "-n"|"--name")
+      if [[ -z "$2" ]]; then
+        echo "Usage: realm init --name <name> <type>" | error
+        exit 1
+      fi
+
+      NAME="$2"
+      shift
+      ;;
+    --name=*)
+      NAME="${1#--name=}"
+
+      if [[ -z "$NAME" ]]; then
+        echo "Usage: realm init --name=<name> <type>" | error
+        exit 1
+      fi
+      ;;
+    *)
+      ARGS+=("$1")
+      ;;
+  esac
+  shift
+done
+
+set -- "${ARGS[@]}"
+
+export PROJECT="${NAME:-${PWD##*/}}"
+
+if [[ "$PROJECT" == *[\"\\]* || "$PROJECT" == *[[:cntrl:]]* ]]; then
+  echo "Project name '${PROJECT}' can't contain quotes, backslashes or control characters." | error
+  exit 1
+fi
+
+generated_marker() {
+  case $1 in
+    ".envrc")
+      echo "GENERAGED BY REALM"
+      ;;
+    ".gitignore")
+      echo "Generated by Realm"
+      ;;
+    ".vscode/settings.json")
+      echo "psi-header.variables"
+      ;;
+    ".vscode/launch.json")
+      echo "--no-sound-null-safety"
+      ;;
+  esac
+}
+
+backup_generated() {
+  local file="$(pwd)/$1"
+  local marker="$(generated_marker "$1")"
+  local backup="${file}.bak"
+  local n=1
+
+  if [[ ! -f "$file" ]]; then
+    return
+  fi
+
+  if ! grep -q -- "$marker" "$file"; then
+    echo "Skipping ${file}, it was not generated by realm." | warning
+    return
+  fi
+
+  while [[ -e "$backup" ]]; do
+    backup="${file}.bak.${n}"
+    n=$((n + 1))
+  done
+
+  echo "Moving ${file} to ${backup}..." | status
+  mv "$file" "$backup"
+}
 
 arg=$1
 shift
 
+if [[ -z "$arg" && $DETECT -eq 1 ]]; then
+  arg="$(project_type)"
+
+  if [[ -z "$arg" ]]; then
+    echo "Could not detect the project type, pass one of: flutter, go, node, rust." | error
+    exit 1
+  fi
+fi
+
 case $arg in
   "flutter")
-    source "${SCRIPT_ENVS_DIR}/__flutter.sh"
+    MARKER="pubspec.yaml"
+    GENERATED=(".gitignore" ".vscode/settings.json" ".vscode/launch.json")
+    ;;
+  "go")
+    MARKER="go.mod"
+    GENERATED=(".envrc" ".gitignore" ".vscode/settings.json")
     ;;
   "node")
-    source "${SCRIPT_ENVS_DIR}/__node.sh"
+    MARKER="package.json"
+    GENERATED=(".envrc" ".gitignore" ".vscode/settings.json")
     ;;
   "rust")
-    source "${SCRIPT_ENVS_DIR}/__rust.sh"
+    MARKER="Cargo.toml"
+    GENERATED=(".envrc" ".gitignore" ".vscode/settings.json")
     ;;
   *)
     echo "Invalid init command." | error
+    exit 1
     ;;
 esac
 
+if [[ ! -f "$(pwd)/${MARKER}" ]]; then
+  echo "No ${MARKER} found in $(pwd), is this a ${arg} project?" | error
+  exit 1
+fi
+
+if [[ $FORCE -eq 1 ]]; then
+  for file in "${GENERATED[@]}"; do
+    backup_generated "$file"
+  done
+
+  echo "Copy any changes of your own back from the .bak files." | info
+fi
+
+source "${SCRIPT_ENVS_DIR}/__${arg}.sh"
+
+unset FORCE
+unset MARKER
+unset GENERATED
+unset file
+unset DETECT
+unset NAME
+unset ARGS
+unset PROJECT
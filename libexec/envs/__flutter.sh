@@ -8,7 +8,7 @@
 # 
 # Copywrite (c) 2021 Wess.io
 #
-OUT_DIR="$(pwd)/.vscode/launch.json"
+LAUNCH_FILE="$(pwd)/.vscode/launch.json"
 PUBSPEC="$(pwd)/pubspec.yaml"
 
 define LAUNCH_CONFIG <<EOF
@@ -97,15 +97,15 @@ if [[ -f "$PUBSPEC" ]]; then
   echo "Setting up for Flutter/Dart development..." | status
   source "${SCRIPT_ENVS_DIR}/__vscode_settings.sh"
 
-  if [ ! -f $OUT_DIR ]; then
+  if [ ! -f "$LAUNCH_FILE" ]; then
     echo "Creating initial VSCode launch settings for flutter..." | status
     
-    _dir="$(dirname -- "$OUT_DIR")"
+    _dir="$(dirname -- "$LAUNCH_FILE")"
     [ -d "$_dir" ] || mkdir -p -- "$_dir"
-    touch -- "$OUT_DIR"
+    touch -- "$LAUNCH_FILE"
 
 
-    echo "$LAUNCH_CONFIG" >> $OUT_DIR
+    echo "$LAUNCH_CONFIG" >> "$LAUNCH_FILE"
   fi
 
   GIT_IGNORE="$(pwd)/.gitignore"
@@ -117,6 +117,6 @@ if [[ -f "$PUBSPEC" ]]; then
   echo "$FLUTTER_IGNORE" >> $GIT_IGNORE
 fi
 
-unset OUT_DIR
+unset LAUNCH_FILE
 unset PUBSPEC
 unset LAUNCH_CONFIG
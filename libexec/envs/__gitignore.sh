@@ -9,7 +9,7 @@
 # Copywrite (c) 2021 Wess.io
 #
 
-define GIT_IGNORE <<EOF
+define GITIGNORE_CONTENT <<EOF
 #### Generated by Realm
 ### macOS ###
 # General
@@ -51,5 +51,7 @@ EOF
 OUT_DIR="$(pwd)/.gitignore"
 
 if [ ! -f $OUT_DIR ]; then
-  echo "$GIT_IGNORE" >> $OUT_DIR
-fi
\ No newline at end of file
+  echo "$GITIGNORE_CONTENT" >> $OUT_DIR
+fi
+
+unset GITIGNORE_CONTENT
\ No newline at end of file
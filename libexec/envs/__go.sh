@@ -0,0 +1,59 @@
+#!/usr/bin/env bash
+#
+# __go.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+
+PROJECT_FILE="$(pwd)/go.mod"
+
+source "${SCRIPT_ENVS_DIR}/__direnv.sh"
+
+define GO_IGNORE <<EOF
+
+### Go ###
+# Binaries for programs and plugins
+*.exe
+*.exe~
+*.dll
+*.so
+*.dylib
+
+# Test binary, built with 'go test -c'
+*.test
+
+# Output of the go coverage tool, specifically when used with LiteIDE
+*.out
+
+# Dependency directories (remove the comment below to include it)
+# vendor/
+
+# Go workspace file
+go.work
+
+EOF
+
+GITIGNORE_FILE="$(pwd)/.gitignore"
+
+if [[ -f "$PROJECT_FILE" ]]; then
+  echo "Setting up for Go development..." | status
+  source "${SCRIPT_ENVS_DIR}/__vscode_settings.sh"
+
+  if [ ! -f "$GITIGNORE_FILE" ]; then
+    source ${SCRIPT_ENVS_DIR}/__gitignore.sh
+  fi
+
+  if ! grep -q "^### Go ###$" "$GITIGNORE_FILE" 2>/dev/null; then
+    echo "$GO_IGNORE" >> "$GITIGNORE_FILE"
+  fi
+fi
+
+
+
+unset PROJECT_FILE
+unset GITIGNORE_FILE
+unset GO_IGNORE
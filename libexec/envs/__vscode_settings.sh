@@ -15,7 +15,7 @@ export REALM_EMAIL="me@wess.io"
 export REALM_COMPANY="Wess.io"
 export REALM_EDITOR="vim"
 
-PROJECT=${PWD##*/}
+PROJECT=${PROJECT:-${PWD##*/}}
 
 define PSI_CONFIG <<EOF
 {
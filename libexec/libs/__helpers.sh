@@ -2,3 +2,17 @@
 
 define(){ IFS='\n' read -r -d '' ${1} || true; }
 
+project_type() {
+  local dir="${1:-$(pwd)}"
+
+  if [[ -f "${dir}/pubspec.yaml" ]]; then
+    echo "flutter"
+  elif [[ -f "${dir}/package.json" ]]; then
+    echo "node"
+  elif [[ -f "${dir}/Cargo.toml" ]]; then
+    echo "rust"
+  elif [[ -f "${dir}/go.mod" ]]; then
+    echo "go"
+  fi
+}
+
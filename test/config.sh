@@ -0,0 +1,114 @@
+#!/usr/bin/env bash
+#
+# config.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+# Run with: bash test/config.sh
+#
+
+TEST_ROOT="$( cd "$( dirname "${BASH_SOURCE[0]}" )/.." >/dev/null 2>&1 && pwd )"
+TEST_HOME="$(mktemp -d)"
+FAILURES=0
+
+trap 'rm -rf "$TEST_HOME"' EXIT
+
+export HOME="$TEST_HOME"
+export PATH="${TEST_ROOT}/libexec:${PATH}"
+unset REALM_CONFIG
+
+mkdir -p "${HOME}/.realm"
+cat > "${HOME}/.realm/config.sh" <<'EOF'
+#!/usr/bin/env bash
+export REALM_AUTHOR="wess"
+export REALM_NAME="wess"
+export REALM_EMAIL="me@wess.io"
+export REALM_COMPANY="Wess.io"
+export REALM_EDITOR="vim"
+EOF
+
+assert_eq() {
+  local name="$1"
+  local expected="$2"
+  local actual="$3"
+
+  if [[ "$expected" == "$actual" ]]; then
+    echo "ok - ${name}"
+  else
+    echo "not ok - ${name}: expected '${expected}', got '${actual}'"
+    FAILURES=$((FAILURES + 1))
+  fi
+}
+
+realm config validate >/dev/null
+assert_eq "validate accepts the default config" "0" "$?"
+
+VALUE='Foo "Bar" $HOME `x` \y'
+
+realm config set REALM_COMPANY "$VALUE" >/dev/null
+realm config set OTHER x >/dev/null
+
+assert_eq "set/set/get keeps a default key's value" "$VALUE" "$(realm config get REALM_COMPANY)"
+assert_eq "set/set/get keeps the new key" "x" "$(realm config get OTHER)"
+assert_eq "config file sources back to the literal value" "$VALUE" "$(bash -c 'source "$HOME/.realm/config.sh"; printf "%s" "$REALM_COMPANY"')"
+
+realm config validate >/dev/null
+assert_eq "config stays valid after repeated sets" "0" "$?"
+
+BEFORE="$(cat "${HOME}/.realm/config.sh")"
+
+realm config set ML $'line1\nline2' >/dev/null 2>&1
+assert_eq "set rejects values with newlines" "1" "$?"
+assert_eq "rejected set leaves the file untouched" "$BEFORE" "$(cat "${HOME}/.realm/config.sh")"
+
+realm config set 'A B' x >/dev/null 2>&1
+assert_eq "set rejects names with spaces" "1" "$?"
+
+realm config set 'X;touch pwned' x >/dev/null 2>&1
+assert_eq "set rejects names with shell syntax" "1" "$?"
+assert_eq "rejected set leaves the file untouched" "$BEFORE" "$(cat "${HOME}/.realm/config.sh")"
+
+OUT="$(realm config get 'REALM_.*' 2>/dev/null)"
+assert_eq "get rejects regex names" "1" "$?"
+assert_eq "get prints nothing on stdout for invalid names" "" "$OUT"
+
+realm config del 'A B' >/dev/null 2>&1
+assert_eq "del rejects invalid names" "1" "$?"
+
+OUT="$(realm config get MISSING 2>/dev/null)"
+assert_eq "get fails for a missing key" "1" "$?"
+assert_eq "get sends the missing key error to stderr" "" "$OUT"
+
+realm config set CTRL $'a\tb\001c "d" \\e' >/dev/null
+assert_eq "get --json escapes control characters" '"a\tb\u0001c \"d\" \\e"' "$(realm config get CTRL --json)"
+
+if command -v python3 >/dev/null 2>&1; then
+  realm config print --json | python3 -c 'import json, sys; json.load(sys.stdin)'
+  assert_eq "print --json is valid JSON" "0" "$?"
+fi
+
+realm config del CTRL >/dev/null
+
+OUT="$(realm config gte FOO 2>/dev/null)"
+assert_eq "unknown config commands fail" "1" "$?"
+assert_eq "unknown config commands print nothing on stdout" "" "$OUT"
+
+realm config print --jsn >/dev/null 2>&1
+assert_eq "print rejects unknown options" "1" "$?"
+
+realm config set DATABASE_PASSWORD hunter2 >/dev/null
+assert_eq "print masks secret values" 'export DATABASE_PASSWORD="****"' "$(realm config print | grep '^export DATABASE_PASSWORD=')"
+assert_eq "print --show-secrets shows them" 'export DATABASE_PASSWORD="hunter2"' "$(realm config print --show-secrets | grep '^export DATABASE_PASSWORD=')"
+assert_eq "get returns the real secret" "hunter2" "$(realm config get DATABASE_PASSWORD)"
+assert_eq "the applied env keeps the real secret" "hunter2" "$(bash -c 'source "$HOME/.realm/config.sh"; printf "%s" "$DATABASE_PASSWORD"')"
+realm config del DATABASE_PASSWORD >/dev/null
+
+echo 'export REALM_COMPANY="Foo "Bar" $HOME"' >> "${HOME}/.realm/config.sh"
+realm config validate >/dev/null
+assert_eq "validate rejects unescaped quotes and \$" "1" "$?"
+
+exit $FAILURES
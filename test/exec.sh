@@ -0,0 +1,104 @@
+#!/usr/bin/env bash
+#
+# exec.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+# Run with: bash test/exec.sh
+#
+
+TEST_ROOT="$( cd "$( dirname "${BASH_SOURCE[0]}" )/.." >/dev/null 2>&1 && pwd )"
+TEST_HOME="$(mktemp -d)"
+FAILURES=0
+
+trap 'rm -rf "$TEST_HOME"' EXIT
+
+export HOME="$TEST_HOME"
+export PATH="${TEST_ROOT}/libexec:${PATH}"
+unset REALM_CONFIG
+
+mkdir -p "${HOME}/.realm" "${HOME}/fake-bin" "${HOME}/project/sub"
+cat > "${HOME}/.realm/config.sh" <<'EOF2'
+#!/usr/bin/env bash
+export REALM_AUTHOR="wess"
+export REALM_NAME="wess"
+export REALM_EMAIL="me@wess.io"
+export REALM_COMPANY="Wess.io"
+export REALM_EDITOR="vim"
+export MY_API_TOKEN="hunter2"
+EOF2
+
+# Stands in for direnv: finds the nearest .envrc above the given directory,
+# loads it and runs the command.
+cat > "${HOME}/fake-bin/direnv" <<'EOF2'
+#!/usr/bin/env bash
+[[ "$1" == "exec" ]] || exit 2
+dir="$2"
+shift 2
+
+while [[ -n "$dir" && ! -f "${dir}/.envrc" ]]; do
+  dir="${dir%/*}"
+done
+
+if [[ -f "${dir}/.envrc" ]]; then
+  set -a
+  source "${dir}/.envrc"
+  set +a
+fi
+
+exec "$@"
+EOF2
+chmod +x "${HOME}/fake-bin/direnv"
+
+echo 'export PROJECT_VAR="from-envrc"' > "${HOME}/project/.envrc"
+
+assert_eq() {
+  local name="$1"
+  local expected="$2"
+  local actual="$3"
+
+  if [[ "$expected" == "$actual" ]]; then
+    echo "ok - ${name}"
+  else
+    echo "not ok - ${name}: expected '${expected}', got '${actual}'"
+    FAILURES=$((FAILURES + 1))
+  fi
+}
+
+child_env() {
+  realm exec bash -c "printf '%s' \"\${${1}-unset}\""
+}
+
+cd "${HOME}/project/sub"
+
+export CALLER_VAR="mine"
+unset LANG
+
+(
+  export PATH="${HOME}/fake-bin:${PATH}"
+
+  assert_eq "the child sees the project's .envrc from a subdirectory" "from-envrc" "$(child_env PROJECT_VAR)"
+  assert_eq "the child sees the caller's env" "mine" "$(child_env CALLER_VAR)"
+  assert_eq "the child doesn't see config.sh exports" "unset" "$(child_env MY_API_TOKEN)"
+  assert_eq "the child doesn't see REALM_HOME" "unset" "$(child_env REALM_HOME)"
+  assert_eq "the child doesn't see SCRIPT_ROOT" "unset" "$(child_env SCRIPT_ROOT)"
+  assert_eq "the child keeps the caller's LANG" "unset" "$(child_env LANG)"
+
+  realm exec -- bash -c 'exit 3'
+  assert_eq "exec propagates the exit code" "3" "$?"
+
+  exit $FAILURES
+)
+FAILURES=$((FAILURES + $?))
+
+if ! command -v direnv >/dev/null 2>&1; then
+  ERR="$(realm exec bash -c 'printf "%s" "${PROJECT_VAR-unset}"' 2>&1 >/dev/null)"
+  assert_eq "exec warns when an .envrc exists but direnv is missing" "yes" "$([[ "$ERR" == *"direnv isn't installed"* ]] && echo yes)"
+  assert_eq "exec still runs without direnv" "unset" "$(realm exec bash -c 'printf "%s" "${PROJECT_VAR-unset}"' 2>/dev/null)"
+fi
+
+exit $FAILURES
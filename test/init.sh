@@ -0,0 +1,93 @@
+#!/usr/bin/env bash
+#
+# init.sh
+# realm
+# 
+# Author: Wess Cope (me@wess.io)
+# Created: 10/16/2026
+# 
+# Copywrite (c) 2026 Wess.io
+#
+# Run with: bash test/init.sh
+#
+
+TEST_ROOT="$( cd "$( dirname "${BASH_SOURCE[0]}" )/.." >/dev/null 2>&1 && pwd )"
+TEST_HOME="$(mktemp -d)"
+FAILURES=0
+
+trap 'rm -rf "$TEST_HOME"' EXIT
+
+export HOME="$TEST_HOME"
+export PATH="${TEST_ROOT}/libexec:${PATH}"
+unset REALM_CONFIG
+
+mkdir -p "${HOME}/.realm" "${HOME}/project"
+cat > "${HOME}/.realm/config.sh" <<'EOF'
+#!/usr/bin/env bash
+export REALM_AUTHOR="wess"
+export REALM_NAME="wess"
+export REALM_EMAIL="me@wess.io"
+export REALM_COMPANY="Wess.io"
+export REALM_EDITOR="vim"
+EOF
+
+assert_eq() {
+  local name="$1"
+  local expected="$2"
+  local actual="$3"
+
+  if [[ "$expected" == "$actual" ]]; then
+    echo "ok - ${name}"
+  else
+    echo "not ok - ${name}: expected '${expected}', got '${actual}'"
+    FAILURES=$((FAILURES + 1))
+  fi
+}
+
+listing() {
+  find . -type f | sort
+}
+
+cd "${HOME}/project"
+touch Cargo.toml
+
+realm init rust >/dev/null
+echo "mine/" >> .gitignore
+echo '{"args": ["--no-sound-null-safety"]}' > .vscode/launch.json
+
+realm init rust >/dev/null
+assert_eq "init without --force keeps user edits" "1" "$(grep -c '^mine/$' .gitignore)"
+assert_eq "init without --force makes no backups" "0" "$(find . -name '*.bak*' | wc -l | tr -d ' ')"
+
+realm init --force rust >/dev/null
+assert_eq "--force backs up the old .gitignore" "1" "$(grep -c '^mine/$' .gitignore.bak)"
+assert_eq "--force regenerates .gitignore" "0" "$(grep -c '^mine/$' .gitignore)"
+assert_eq "--force backs up settings.json" "yes" "$([[ -f .vscode/settings.json.bak ]] && echo yes)"
+assert_eq "--force rust leaves launch.json alone" "no" "$([[ -f .vscode/launch.json.bak ]] && echo yes || echo no)"
+
+realm init --force rust >/dev/null
+assert_eq "a second --force keeps the first backup" "1" "$(grep -c '^mine/$' .gitignore.bak)"
+assert_eq "a second --force uses a new backup name" "yes" "$([[ -f .gitignore.bak.1 ]] && echo yes)"
+
+BEFORE="$(listing)"
+
+realm init --force rsut >/dev/null
+assert_eq "--force with an invalid type fails" "1" "$?"
+assert_eq "--force with an invalid type moves nothing" "$BEFORE" "$(listing)"
+
+rm Cargo.toml
+BEFORE="$(listing)"
+
+realm init --force rust >/dev/null
+assert_eq "--force without Cargo.toml fails" "1" "$?"
+realm init --force --detect >/dev/null
+assert_eq "--force --detect with nothing detected fails" "1" "$?"
+assert_eq "failed --force runs move nothing" "$BEFORE" "$(listing)"
+
+touch Cargo.toml
+echo "custom" > .envrc
+
+realm init --force rust >/dev/null
+assert_eq "--force skips files realm didn't generate" "custom" "$(cat .envrc)"
+
+exit $FAILURES